@@ -5,9 +5,18 @@
 //!
 //! # Usage
 //!
-//! To get started, create a `Toasts` instance in your rendering code and specify the anchor position and
-//! direction for the notifications. Toast notifications will show up starting from the specified
-//! anchor position and stack up in the specified direction.
+//! Create a single `Toasts` instance, e.g. stored in your application struct, and specify the
+//! anchor position and direction for the notifications. Toast notifications will show up starting
+//! from the specified anchor position and stack up in the specified direction. Call
+//! [`Toasts::show()`] once per frame to draw and update them; unlike before, `Toasts` is no longer
+//! rebuilt every frame, so builder options such as [`Toasts::anchor()`], [`Toasts::direction()`]
+//! and [`Toasts::custom_contents()`] only need to be set up once, when the instance is created.
+//!
+//! **Breaking change:** `Toasts` used to be rebuilt with `Toasts::new()...` every frame, with only
+//! the toast list itself persisted behind the scenes in [`Context::data`]. If your code still does
+//! that, every toast you added will be gone by the next frame, since the list now lives on the
+//! `Toasts` instance itself and a freshly built one starts empty. Keep a single `Toasts` around
+//! (e.g. as a field on your app) instead of constructing it inside your update/draw function.
 //!
 //! To add a toast, you can use one of the convenience methods for different [ToastKinds](ToastKind),
 //! e.g. [`Toasts::info()`] for info notifications. You can also use [`Toasts::add()`] if you would like to specify the toast kind
@@ -159,16 +168,70 @@ impl Toast {
 
 pub type ToastContents = dyn Fn(&mut Ui, &mut Toast) -> Response;
 
+/// Default duration of the slide-in/fade-out animation played when a toast appears or expires.
+pub const DEFAULT_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// Eases `t` so the animation starts fast and settles gently into place.
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Fades everything drawn with `ui`'s visuals by `opacity`, approximating a whole-subtree
+/// opacity multiplier. `Ui::set_opacity` isn't available until egui 0.27, which also drops the
+/// non-closure `ctx.input()` call the rest of this crate relies on, so this fades
+/// the style colors widgets actually paint with instead. Callers that paint with explicit colors
+/// (e.g. the toast kind icon) still need to fade those themselves with [`Color32::linear_multiply`].
+fn fade_visuals(ui: &mut Ui, opacity: f32) {
+    let visuals = ui.visuals_mut();
+    visuals.override_text_color = Some(visuals.text_color().linear_multiply(opacity));
+    for widget_visuals in [
+        &mut visuals.widgets.noninteractive,
+        &mut visuals.widgets.inactive,
+        &mut visuals.widgets.hovered,
+        &mut visuals.widgets.active,
+        &mut visuals.widgets.open,
+    ] {
+        widget_visuals.bg_fill = widget_visuals.bg_fill.linear_multiply(opacity);
+        widget_visuals.bg_stroke.color = widget_visuals.bg_stroke.color.linear_multiply(opacity);
+        widget_visuals.fg_stroke.color = widget_visuals.fg_stroke.color.linear_multiply(opacity);
+    }
+    visuals.window_fill = visuals.window_fill.linear_multiply(opacity);
+    visuals.window_stroke.color = visuals.window_stroke.color.linear_multiply(opacity);
+}
+
+/// Bookkeeping kept alongside a [`Toast`] across frames: its current slide/fade animation
+/// progress, and the rendered width of its last frame (used to size the slide-in offset before
+/// the toast has been laid out again). Lives as long as the toast does, since `Toasts` itself is
+/// now the long-lived store.
+#[derive(Clone)]
+struct ToastState {
+    toast: Toast,
+    width: f32,
+    /// Animation progress in `[0, 1]`, eased with [`ease_out_cubic`] before use.
+    anim_t: f32,
+    /// The duration the toast was created with, held back while it sits in the overflow queue
+    /// so its expiry clock doesn't run before anyone can see it.
+    pending_duration: Option<Duration>,
+    /// Whether this toast's `created_at`/`expires_at` clock has started, i.e. it has been
+    /// promoted out of the overflow queue and shown at least once.
+    started: bool,
+}
+
 pub struct Toasts {
     id: Id,
     anchor: Pos2,
     direction: Direction,
     align_to_end: bool,
     custom_toast_contents: HashMap<ToastKind, Box<ToastContents>>,
-    toasts: Vec<Toast>,
+    toasts: Vec<ToastState>,
     progress_bar_color: Color32,
     progress_bar_width: f32,
     progress_bar_outline_color: Color32,
+    animation_duration: Duration,
+    pause_on_hover: bool,
+    custom_levels: HashMap<ToastKind, (String, Color32)>,
+    max_visible: Option<usize>,
+    area_pos: Pos2,
 }
 
 impl Default for Toasts {
@@ -189,6 +252,11 @@ impl Toasts {
             progress_bar_color: Color32::DARK_GREEN,
             progress_bar_width: 0.0,
             progress_bar_outline_color: Color32::LIGHT_GRAY,
+            animation_duration: DEFAULT_ANIMATION_DURATION,
+            pause_on_hover: true,
+            custom_levels: HashMap::new(),
+            max_visible: None,
+            area_pos: Pos2::new(0.0, 0.0),
         }
     }
 
@@ -218,6 +286,28 @@ impl Toasts {
         self
     }
 
+    /// Duration of the slide-in/fade-out animation played when a toast appears or is about to
+    /// expire. Pass [`Duration::ZERO`] to disable animations entirely.
+    pub fn animation_duration(mut self, duration: Duration) -> Self {
+        self.animation_duration = duration;
+        self
+    }
+
+    /// Whether to pause a toast's expiry countdown while the pointer hovers over it. Defaults to
+    /// `true`.
+    pub fn pause_on_hover(mut self, pause_on_hover: bool) -> Self {
+        self.pause_on_hover = pause_on_hover;
+        self
+    }
+
+    /// Caps how many toasts are rendered at once. Toasts beyond the cap stay queued and are
+    /// promoted into view, in order, as visible ones expire or are dismissed. A queued toast's
+    /// expiry countdown only starts once it is actually promoted.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
+
     /// Can be used to specify a custom rendering function for toasts for given kind
     pub fn custom_contents(
         mut self,
@@ -229,6 +319,23 @@ impl Toasts {
         self
     }
 
+    /// Registers a label and accent color for a [`ToastKind::Custom`] level, so it gets the
+    /// default frame, icon slot, close button and progress bar, just with its own look instead
+    /// of falling back to the info styling. A lighter-weight alternative to [`Self::custom_contents`]
+    /// for when all you need is a differently labelled/colored notification. The label is plain
+    /// text tinted with `color`; it doesn't carry its own font or style, so pass a string rather
+    /// than a pre-styled [`RichText`].
+    pub fn custom_level(
+        mut self,
+        kind: impl Into<ToastKind>,
+        label_or_icon: impl Into<String>,
+        color: Color32,
+    ) -> Self {
+        self.custom_levels
+            .insert(kind.into(), (label_or_icon.into(), color));
+        self
+    }
+
     /// Adds a new info toast
     pub fn info(
         &mut self,
@@ -283,10 +390,42 @@ impl Toasts {
 
     /// Adds a new toast
     pub fn add(&mut self, toast: Toast) -> &mut Self {
-        self.toasts.push(toast);
+        let mut toast = toast;
+
+        // Hold the requested duration back until the toast is actually promoted out of the
+        // overflow queue (see `max_visible`), so it can't expire before anyone sees it. A toast
+        // with an expiry but no creation time is measured from now.
+        let pending_duration = toast.options.expires_at.map(|expires_at| {
+            expires_at - toast.options.created_at.unwrap_or_else(Instant::now)
+        });
+        toast.options.expires_at = None;
+        toast.options.created_at = None;
+
+        self.toasts.push(ToastState {
+            toast,
+            width: 0.0,
+            anim_t: 0.0,
+            pending_duration,
+            started: false,
+        });
         self
     }
 
+    /// Dismisses every toast, playing its close animation rather than removing it outright.
+    pub fn dismiss_all(&mut self) {
+        for state in &mut self.toasts {
+            state.toast.close();
+        }
+    }
+
+    /// Dismisses the oldest toast still around (including queued ones), playing its close
+    /// animation rather than removing it outright.
+    pub fn dismiss_oldest(&mut self) {
+        if let Some(state) = self.toasts.first_mut() {
+            state.toast.close();
+        }
+    }
+
     /// Shows and updates all toasts
     pub fn show(&mut self, ctx: &Context) {
         let Self {
@@ -297,16 +436,30 @@ impl Toasts {
             progress_bar_color,
             progress_bar_width,
             progress_bar_outline_color,
+            animation_duration,
+            pause_on_hover,
+            max_visible,
+            area_pos,
             ..
         } = *self;
-
-        let mut toasts: Vec<Toast> = ctx.data().get_temp(id).unwrap_or_default();
-        toasts.extend(std::mem::take(&mut self.toasts));
+        let custom_levels = self.custom_levels.clone();
+
+        // Side the toasts slide in from/out to, horizontally, derived from which screen edge
+        // they're anchored to.
+        let anim_side: f32 = match direction {
+            Direction::LeftToRight => -1.0,
+            Direction::RightToLeft => 1.0,
+            Direction::TopDown | Direction::BottomUp => {
+                if align_to_end {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+        };
 
         let screen_area = ctx.available_rect();
 
-        let area_pos: Pos2 = ctx.data().get_temp(id.with("pos")).unwrap_or_default();
-
         Area::new(id.with("area"))
             .fixed_pos(area_pos)
             .order(Order::Foreground)
@@ -345,41 +498,131 @@ impl Toasts {
                         Layout::from_main_dir_and_cross_align(direction, cross_align),
                         |ui| {
                             ui.spacing_mut().item_spacing = Vec2::splat(5.0);
-                            for toast in toasts.iter_mut() {
-                                let toast_response = if let Some(add_contents) =
-                                self.custom_toast_contents.get_mut(&toast.kind)
-                                {
-                                    add_contents(ui, toast)
+
+                            let dt = ctx.input().unstable_dt;
+
+                            // A toast is still occupying a slot as long as it would survive the
+                            // `retain` sweep below, i.e. while it's either not yet expired or
+                            // still mid-exit-animation. Mirroring that condition here (rather
+                            // than just checking `expires_at`) keeps a closed toast's slot held
+                            // until it has actually faded out, instead of freeing it the instant
+                            // `close()`/`dismiss_all` sets `expires_at` and letting a queued toast
+                            // be promoted on top of it.
+                            let is_holding_slot = |state: &ToastState| match state.toast.options.expires_at {
+                                None => true,
+                                Some(expires_at) => {
+                                    if animation_duration.is_zero() {
+                                        expires_at > now
+                                    } else {
+                                        expires_at > now || state.anim_t > f32::EPSILON
+                                    }
+                                }
+                            };
+
+                            // Toasts already on screen keep their slot even while fading out;
+                            // only the remaining slots are handed out to queued toasts below.
+                            let active_count = self
+                                .toasts
+                                .iter()
+                                .filter(|state| state.started && is_holding_slot(state))
+                                .count();
+                            let mut free_slots = max_visible.map(|limit| limit.saturating_sub(active_count));
+
+                            for state in self.toasts.iter_mut() {
+                                if !state.started {
+                                    match &mut free_slots {
+                                        Some(0) => continue, // still queued, no slot free yet
+                                        Some(n) => *n -= 1,
+                                        None => {}
+                                    }
+                                    state.started = true;
+                                    state.toast.options.created_at = Some(now);
+                                    // `dismiss_all`/`dismiss_oldest` may have already closed this
+                                    // toast while it was still queued, which sets `expires_at` to
+                                    // the close time; don't clobber that with its full duration.
+                                    if state.toast.options.expires_at.is_none() {
+                                        state.toast.options.expires_at =
+                                            state.pending_duration.map(|duration| now + duration);
+                                    }
+                                }
+
+                                // A toast starts sliding/fading out `animation_duration` before
+                                // it actually expires, so it has fully vanished by the time it
+                                // would otherwise have been removed. `Toast::close()` sets
+                                // `expires_at` to now, so this also drives the close animation.
+                                let closing = !animation_duration.is_zero()
+                                    && state.toast.options.expires_at.is_some_and(|expires_at| {
+                                    now + animation_duration >= expires_at
+                                });
+                                let target: f32 = if closing { 0.0 } else { 1.0 };
+
+                                let prev_t = state.anim_t;
+                                let t = if animation_duration.is_zero() {
+                                    target
                                 } else {
-                                    let window = default_toast_contents(ui, toast);
-                                    let rect = window.response.rect; // Get the size of the toast window
-                                    add_progress_bar_layer(toast, ctx, rect, progress_bar_color, progress_bar_width, progress_bar_outline_color); // Add the progress bar layer
-                                    window.response // Show the toast window
+                                    let step = dt / animation_duration.as_secs_f32();
+                                    if target > prev_t {
+                                        (prev_t + step).min(target)
+                                    } else {
+                                        (prev_t - step).max(target)
+                                    }
                                 };
-
+                                state.anim_t = t;
+
+                                let eased = ease_out_cubic(t);
+                                let offset = (1.0 - eased) * anim_side * state.width;
+
+                                let toast_response = ui
+                                    .scope(|ui| {
+                                        fade_visuals(ui, eased);
+                                        ui.horizontal(|ui| {
+                                            ui.add_space(offset);
+                                            if let Some(add_contents) =
+                                            self.custom_toast_contents.get_mut(&state.toast.kind)
+                                            {
+                                                add_contents(ui, &mut state.toast)
+                                            } else {
+                                                let window = default_toast_contents(ui, &mut state.toast, &custom_levels, eased);
+                                                let rect = window.response.rect; // Get the size of the toast window
+                                                add_progress_bar_layer(&mut state.toast, ctx, rect, progress_bar_color, progress_bar_width, progress_bar_outline_color); // Add the progress bar layer
+                                                window.response // Show the toast window
+                                            }
+                                        })
+                                    })
+                                    .inner
+                                    .inner;
+
+                                // Freeze the remaining-time fraction while the pointer is over
+                                // the toast by pushing both timestamps forward by the same
+                                // amount, so the expiry and the progress bar stay in sync.
+                                if pause_on_hover && toast_response.hovered() {
+                                    let paused_dt = Duration::from_secs_f32(dt.max(0.0));
+                                    if let Some(expires_at) = state.toast.options.expires_at {
+                                        state.toast.options.expires_at = Some(expires_at + paused_dt);
+                                    }
+                                    if let Some(created_at) = state.toast.options.created_at {
+                                        state.toast.options.created_at = Some(created_at + paused_dt);
+                                    }
+                                }
+
+                                state.width = toast_response.rect.width();
                                 next_area_pos = next_area_pos.min(toast_response.rect.min);
                             }
 
-                            if toasts.is_empty() {
+                            if self.toasts.is_empty() {
                                 next_area_pos = anchor;
                             }
 
-                            ctx.data().insert_temp(id.with("pos"), next_area_pos);
+                            self.area_pos = next_area_pos;
 
-                            toasts.retain(|toast| {
-                                toast
-                                    .options
-                                    .expires_at
-                                    .filter(|&expires_at| expires_at <= now)
-                                    .is_none()
-                            });
+                            // Only drop a toast once its exit animation has fully completed, so
+                            // it animates away instead of popping out of existence.
+                            self.toasts.retain(is_holding_slot);
 
-                            // Request UI repaint if there are still toasts
-                            if !toasts.is_empty() {
+                            // Request UI repaint while there are still toasts showing or animating.
+                            if !self.toasts.is_empty() {
                                 ctx.request_repaint();
                             }
-
-                            ctx.data().insert_temp(id, toasts);
                         },
                     );
                 });
@@ -387,21 +630,31 @@ impl Toasts {
     }
 }
 
-fn default_toast_contents(ui: &mut Ui, toast: &mut Toast) -> InnerResponse<()> {
+fn default_toast_contents(
+    ui: &mut Ui,
+    toast: &mut Toast,
+    custom_levels: &HashMap<ToastKind, (String, Color32)>,
+    opacity: f32,
+) -> InnerResponse<()> {
     let window = egui::Frame::window(ui.style())
         .inner_margin(10.0)
+        .multiply_with_opacity(opacity)
         .show(ui, |ui| {
             ui.horizontal(|ui| {
-                let (icon, color) = match toast.kind {
-                    ToastKind::Warning => ("âš ", WARNING_COLOR),
-                    ToastKind::Error => ("â—", ERROR_COLOR),
-                    ToastKind::Success => ("âœ”", SUCCESS_COLOR),
-                    _ => ("â„¹", INFO_COLOR),
+                let icon: WidgetText = match toast.kind {
+                    ToastKind::Warning => RichText::new("âš ").color(WARNING_COLOR.linear_multiply(opacity)).into(),
+                    ToastKind::Error => RichText::new("â—").color(ERROR_COLOR.linear_multiply(opacity)).into(),
+                    ToastKind::Success => RichText::new("âœ”").color(SUCCESS_COLOR.linear_multiply(opacity)).into(),
+                    ToastKind::Custom(_) => custom_levels
+                        .get(&toast.kind)
+                        .map(|(label, color)| RichText::new(label.as_str()).color(color.linear_multiply(opacity)).into())
+                        .unwrap_or_else(|| RichText::new("â„¹").color(INFO_COLOR.linear_multiply(opacity)).into()),
+                    ToastKind::Info => RichText::new("â„¹").color(INFO_COLOR.linear_multiply(opacity)).into(),
                 };
 
                 let a = |ui: &mut Ui, toast: &mut Toast| {
                     if toast.options.show_icon {
-                        ui.label(RichText::new(icon).color(color));
+                        ui.label(icon.clone());
                     }
                 };
                 let b = |ui: &mut Ui, toast: &mut Toast| ui.label(toast.text.clone());
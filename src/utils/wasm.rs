@@ -71,8 +71,10 @@ impl Sub<Instant> for Instant {
     type Output = Duration;
 
     fn sub(self, other: Instant) -> Duration {
-        let ms = self.inner - other.inner;
-        assert!(ms >= 0.0);
+        // Mirrors `std::time::Instant`'s subtraction, which saturates to zero instead of
+        // panicking when `other` is later than `self` (e.g. `expires_at - Instant::now()`
+        // once a toast's expiry has passed but it's still fading out).
+        let ms = (self.inner - other.inner).max(0.0);
         Duration::from_millis(ms as u64)
     }
 }
\ No newline at end of file